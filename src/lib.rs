@@ -1,38 +1,93 @@
+// `CoerceUnsized`/`Unsize` are required for `Warc<dyn Trait>`/`Warc<[T]>` support and are not
+// stabilized yet, so this crate currently only builds on nightly.
+#![feature(coerce_unsized, layout_for_ptr, ptr_metadata, unsize)]
+
+use std::alloc::{self, Layout};
 use std::cell::Cell;
 use std::cmp::{self, Eq, Ord, PartialEq, PartialOrd};
 use std::fmt::{self, Debug, Display, Formatter};
-use std::marker::PhantomData;
-use std::ops::Deref;
-use std::ptr::NonNull;
+use std::iter::FromIterator;
+use std::marker::{PhantomData, Unsize};
+use std::mem;
+use std::ops::{CoerceUnsized, Deref};
+use std::ptr::{self, NonNull};
 use std::sync::atomic::{self, AtomicUsize, Ordering};
 
 const DEFAULT_WEIGHT: usize = 1 << 16;
 const ADD_WEIGHT: usize = DEFAULT_WEIGHT - 1;
 
+/// The weight every `Warc` is normalized to before [`into_raw`](Warc::into_raw) leaks it, so that
+/// [`from_raw`](Warc::from_raw) always knows exactly how much weight to reclaim.
+const RAW_WEIGHT: usize = DEFAULT_WEIGHT;
+
 pub struct Warc<T: ?Sized> {
     local: Cell<usize>,
+    local_weak: Cell<usize>,
     inner: NonNull<Inner<T>>,
     _chck: PhantomData<Inner<T>>,
 }
 
+impl<T: ?Sized + Unsize<U>, U: ?Sized> CoerceUnsized<Warc<U>> for Warc<T> {}
+
+#[repr(C)]
+struct Header {
+    global: AtomicUsize,
+    weak: AtomicUsize,
+}
+
+#[repr(C)]
 struct Inner<T: ?Sized> {
     global: AtomicUsize,
-    value: T,
+    weak: AtomicUsize,
+    value: mem::ManuallyDrop<T>,
 }
 
 impl<T> Warc<T> {
     pub fn new(value: T) -> Self {
         let inner = Box::new(Inner {
             global: AtomicUsize::new(DEFAULT_WEIGHT),
-            value,
+            weak: AtomicUsize::new(DEFAULT_WEIGHT),
+            value: mem::ManuallyDrop::new(value),
         });
 
         Warc {
             local: Cell::new(DEFAULT_WEIGHT),
+            local_weak: Cell::new(0),
             inner: Box::leak(inner).into(),
             _chck: PhantomData,
         }
     }
+
+    /// Returns the inner value if `self` is the only reference to it, or `self` back otherwise.
+    pub fn try_unwrap(self) -> Result<T, Self> {
+        let inner = self.inner();
+        let global = inner.global.load(Ordering::Acquire);
+        if global != self.local.get() {
+            return Err(self);
+        }
+
+        if inner.global.compare_exchange(global, 0, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            return Err(self);
+        }
+
+        atomic::fence(Ordering::Acquire);
+
+        let value = mem::ManuallyDrop::into_inner(unsafe { ptr::read(&inner.value) });
+
+        if inner.weak.fetch_sub(DEFAULT_WEIGHT, Ordering::Release) == DEFAULT_WEIGHT {
+            atomic::fence(Ordering::Acquire);
+            unsafe { alloc::dealloc(self.inner.as_ptr().cast(), Layout::new::<Inner<T>>()) };
+        }
+
+        mem::forget(self);
+
+        Ok(value)
+    }
+
+    /// Returns the inner value if `self` is the only reference to it, dropping `self` otherwise.
+    pub fn into_inner(self) -> Option<T> {
+        self.try_unwrap().ok()
+    }
 }
 
 impl<T: ?Sized> Warc<T> {
@@ -41,21 +96,185 @@ impl<T: ?Sized> Warc<T> {
     }
 
     #[cfg(test)]
-    fn local(&self) -> usize {
+    fn weak(&self) -> usize {
+        self.inner().weak.load(Ordering::Acquire)
+    }
+
+    /// Returns the weight this handle holds out of the current total [`weight`](Warc::weight).
+    pub fn local_weight(&self) -> usize {
         self.local.get()
     }
 
-    #[cfg(test)]
-    fn global(&self) -> usize {
+    /// Returns the current total weight shared by every live strong handle to this allocation
+    /// (the weighted-counting equivalent of `Arc::strong_count`).
+    pub fn weight(&self) -> usize {
         self.inner().global.load(Ordering::Acquire)
     }
+
+    /// Returns `true` if `a` and `b` point to the same allocation.
+    pub fn ptr_eq(a: &Self, b: &Self) -> bool {
+        ptr::eq(a.inner.as_ptr(), b.inner.as_ptr())
+    }
+
+    /// Consumes the `Warc`, returning a raw pointer to the contained value.
+    ///
+    /// The weight held by `self` is normalized to [`RAW_WEIGHT`] and leaked; it must be reclaimed
+    /// by calling [`from_raw`](Warc::from_raw) on the returned pointer, or the allocation is
+    /// leaked forever. Other strong handles to the same allocation may be freely cloned or
+    /// dropped while the pointer is in flight.
+    pub fn into_raw(self) -> *const T {
+        let local = self.local.get();
+        match local.cmp(&RAW_WEIGHT) {
+            cmp::Ordering::Less => {
+                let inner = self.inner();
+                let mint = RAW_WEIGHT - local;
+                let mut current_global = inner.global.load(Ordering::Acquire);
+                let mut new_global;
+                loop {
+                    if current_global <= usize::MAX - mint {
+                        new_global = current_global + mint;
+                    } else {
+                        panic!("global weight is too high");
+                    }
+
+                    match inner.global.compare_exchange_weak(current_global, new_global, Ordering::AcqRel, Ordering::Acquire) {
+                        Ok(_) => break,
+                        Err(global) => current_global = global,
+                    }
+                }
+            }
+            cmp::Ordering::Greater => {
+                self.inner().global.fetch_sub(local - RAW_WEIGHT, Ordering::Release);
+            }
+            cmp::Ordering::Equal => {}
+        }
+
+        let release_weak = self.reserved_weak();
+        if release_weak != 0 {
+            self.inner().weak.fetch_sub(release_weak, Ordering::Release);
+        }
+
+        let ptr = unsafe { &*self.inner.as_ref().value as *const T };
+        mem::forget(self);
+        ptr
+    }
+
+    /// Reconstructs a `Warc` previously turned into a raw pointer by [`into_raw`](Warc::into_raw).
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been obtained from [`Warc::into_raw`], and this function must be called at
+    /// most once per weight leaked that way.
+    pub unsafe fn from_raw(ptr: *const T) -> Self {
+        let value_layout = unsafe { Layout::for_value_raw(ptr) };
+        let (_, value_offset) = Layout::new::<Header>().extend(value_layout).unwrap();
+
+        let metadata = ptr::metadata(ptr);
+        let base = unsafe { ptr.cast::<u8>().sub(value_offset) }.cast_mut();
+        let inner: *mut Inner<T> = ptr::from_raw_parts_mut(base, metadata);
+
+        Warc {
+            local: Cell::new(RAW_WEIGHT),
+            local_weak: Cell::new(0),
+            inner: unsafe { NonNull::new_unchecked(inner) },
+            _chck: PhantomData,
+        }
+    }
+
+    /// Creates a new `Weak` pointer to this allocation.
+    pub fn downgrade(&self) -> Weak<T> {
+        let mut local_weak = self.local_weak.get();
+        // `local_weak` must be topped up to `DEFAULT_WEIGHT` (a power of two) before it's ever
+        // halved, so that halving never loses a unit and never hands out a zero-weight `Weak`;
+        // the pool then holds 0 or 1 unit, and only the shortfall up to `DEFAULT_WEIGHT` needs to
+        // be minted into the shared counter.
+        if local_weak <= 1 {
+            let mint = DEFAULT_WEIGHT - local_weak;
+            let inner = self.inner();
+            let mut current_weak = inner.weak.load(Ordering::Acquire);
+            let mut new_weak;
+            loop {
+                if current_weak <= usize::MAX - mint {
+                    new_weak = current_weak + mint;
+                } else {
+                    panic!("weak weight is too high");
+                }
+
+                match inner.weak.compare_exchange_weak(current_weak, new_weak, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => break,
+                    Err(weak) => current_weak = weak,
+                }
+            }
+
+            local_weak = DEFAULT_WEIGHT;
+        }
+
+        local_weak >>= 1;
+        self.local_weak.set(local_weak);
+
+        Weak {
+            local: Cell::new(local_weak),
+            inner: self.inner,
+            _chck: PhantomData,
+        }
+    }
+
+    /// Returns the portion of [`weak`](Inner::weak) that this handle has minted for its own
+    /// `downgrade` pool but has not yet handed out to any `Weak` (`0` if nothing was minted).
+    fn reserved_weak(&self) -> usize {
+        self.local_weak.get()
+    }
+
+    /// Returns a mutable reference to the inner value, if this handle is the only reference to it.
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        if self.inner().global.load(Ordering::Acquire) != self.local.get() {
+            return None;
+        }
+
+        if self.inner().weak.load(Ordering::Acquire) != DEFAULT_WEIGHT + self.reserved_weak() {
+            return None;
+        }
+
+        atomic::fence(Ordering::Acquire);
+
+        Some(unsafe { &mut *self.inner.as_mut().value })
+    }
+}
+
+impl<T: Clone> Warc<T> {
+    /// Returns a mutable reference to the inner value, cloning it into a fresh allocation first
+    /// if this handle is not the only reference to it.
+    pub fn make_mut(&mut self) -> &mut T {
+        let unique = self.inner().global.load(Ordering::Acquire) == self.local.get()
+            && self.inner().weak.load(Ordering::Acquire) == DEFAULT_WEIGHT + self.reserved_weak();
+
+        if !unique {
+            *self = Self::new((**self).clone());
+        }
+
+        unsafe { &mut self.inner.as_mut().value }
+    }
 }
 
 unsafe impl<T: ?Sized + Send + Sync> Send for Warc<T> {}
 
 impl<T: ?Sized> Drop for Warc<T> {
     fn drop(&mut self) {
-        if self.inner().global.fetch_sub(self.local.get(), Ordering::Release) != 0 {
+        let release_weak = self.reserved_weak();
+
+        if self.inner().global.fetch_sub(self.local.get(), Ordering::Release) != self.local.get() {
+            if release_weak != 0 {
+                self.inner().weak.fetch_sub(release_weak, Ordering::Release);
+            }
+
+            return;
+        }
+
+        atomic::fence(Ordering::Acquire);
+
+        unsafe { mem::ManuallyDrop::drop(&mut self.inner.as_mut().value) };
+
+        if self.inner().weak.fetch_sub(DEFAULT_WEIGHT + release_weak, Ordering::Release) != DEFAULT_WEIGHT + release_weak {
             return;
         }
 
@@ -93,6 +312,7 @@ impl<T: ?Sized> Clone for Warc<T> {
 
         Warc {
             local: Cell::new(local),
+            local_weak: Cell::new(0),
             inner: self.inner,
             _chck: PhantomData,
         }
@@ -111,6 +331,54 @@ impl<T: ?Sized> AsRef<T> for Warc<T> {
     }
 }
 
+impl<T: ?Sized> From<Box<T>> for Warc<T> {
+    fn from(value: Box<T>) -> Self {
+        let value_layout = Layout::for_value::<T>(&value);
+        let (layout, value_offset) = Layout::new::<Header>().extend(value_layout).unwrap();
+        let layout = layout.pad_to_align();
+
+        let raw = Box::into_raw(value);
+        let metadata = ptr::metadata(raw as *const T);
+
+        let base = unsafe { alloc::alloc(layout) };
+        if base.is_null() {
+            alloc::handle_alloc_error(layout);
+        }
+
+        unsafe {
+            base.cast::<Header>().write(Header {
+                global: AtomicUsize::new(DEFAULT_WEIGHT),
+                weak: AtomicUsize::new(DEFAULT_WEIGHT),
+            });
+
+            ptr::copy_nonoverlapping(raw.cast::<u8>(), base.add(value_offset), value_layout.size());
+
+            alloc::dealloc(raw.cast::<u8>(), value_layout);
+        }
+
+        let inner = ptr::from_raw_parts_mut::<Inner<T>>(base, metadata);
+
+        Warc {
+            local: Cell::new(DEFAULT_WEIGHT),
+            local_weak: Cell::new(0),
+            inner: unsafe { NonNull::new_unchecked(inner) },
+            _chck: PhantomData,
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Warc<[T]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Warc::from(iter.into_iter().collect::<Vec<T>>().into_boxed_slice())
+    }
+}
+
+impl<T: Clone> From<&[T]> for Warc<[T]> {
+    fn from(value: &[T]) -> Self {
+        value.iter().cloned().collect()
+    }
+}
+
 impl<T: ?Sized> Deref for Warc<T> {
     type Target = T;
 
@@ -121,13 +389,13 @@ impl<T: ?Sized> Deref for Warc<T> {
 
 impl<T: Debug + ?Sized> Debug for Warc<T> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        self.inner().value.fmt(fmt)
+        (*self.inner().value).fmt(fmt)
     }
 }
 
 impl<T: Display + ?Sized> Display for Warc<T> {
     fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
-        self.inner().value.fmt(fmt)
+        (*self.inner().value).fmt(fmt)
     }
 }
 
@@ -151,6 +419,75 @@ impl<T: PartialOrd + ?Sized> PartialOrd for Warc<T> {
     }
 }
 
+/// A weak, non-owning reference to a [`Warc`]'s value.
+///
+/// Unlike `Warc`, a `Weak` does not keep the value alive: it must be [`upgrade`](Weak::upgrade)d
+/// back into a `Warc` before the value can be accessed, which fails once every strong handle has
+/// been dropped.
+pub struct Weak<T: ?Sized> {
+    local: Cell<usize>,
+    inner: NonNull<Inner<T>>,
+    _chck: PhantomData<Inner<T>>,
+}
+
+impl<T: ?Sized> Weak<T> {
+    fn inner(&self) -> &Inner<T> {
+        unsafe { self.inner.as_ref() }
+    }
+
+    #[cfg(test)]
+    fn local(&self) -> usize {
+        self.local.get()
+    }
+
+    /// Attempts to upgrade this `Weak` into a `Warc`, returning `None` if the value has already
+    /// been dropped.
+    pub fn upgrade(&self) -> Option<Warc<T>> {
+        let inner = self.inner();
+        let mut current_global = inner.global.load(Ordering::Acquire);
+        loop {
+            if current_global == 0 {
+                return None;
+            }
+
+            match inner.global.compare_exchange_weak(
+                current_global,
+                current_global + ADD_WEIGHT,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    return Some(Warc {
+                        local: Cell::new(ADD_WEIGHT),
+                        local_weak: Cell::new(0),
+                        inner: self.inner,
+                        _chck: PhantomData,
+                    });
+                }
+                Err(global) => current_global = global,
+            }
+        }
+    }
+}
+
+unsafe impl<T: ?Sized + Send + Sync> Send for Weak<T> {}
+
+impl<T: ?Sized> Drop for Weak<T> {
+    fn drop(&mut self) {
+        if self.inner().weak.fetch_sub(self.local.get(), Ordering::Release) != self.local.get() {
+            return;
+        }
+
+        atomic::fence(Ordering::Acquire);
+
+        if self.inner().global.load(Ordering::Acquire) != 0 {
+            return;
+        }
+
+        drop(unsafe { Box::from_raw(self.inner.as_ptr()) })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,17 +495,17 @@ mod tests {
     #[test]
     fn local() {
         let warc = Warc::new(());
-        assert_eq!(warc.local(), DEFAULT_WEIGHT);
-        assert_eq!(warc.global(), DEFAULT_WEIGHT);
+        assert_eq!(warc.local_weight(), DEFAULT_WEIGHT);
+        assert_eq!(warc.weight(), DEFAULT_WEIGHT);
 
         let warcp = warc.clone();
-        assert_eq!(warcp.local(), DEFAULT_WEIGHT >> 1);
-        assert_eq!(warc.local(), DEFAULT_WEIGHT >> 1);
-        assert_eq!(warc.global(), DEFAULT_WEIGHT);
+        assert_eq!(warcp.local_weight(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.weight(), DEFAULT_WEIGHT);
 
         drop(warcp);
-        assert_eq!(warc.local(), DEFAULT_WEIGHT >> 1);
-        assert_eq!(warc.global(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.weight(), DEFAULT_WEIGHT >> 1);
     }
 
     #[test]
@@ -177,29 +514,225 @@ mod tests {
         let mut clones = Vec::with_capacity(16);
 
         for i in 0..16 {
-            assert_eq!(warc.local(), DEFAULT_WEIGHT >> i);
-            assert_eq!(warc.global(), DEFAULT_WEIGHT);
+            assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> i);
+            assert_eq!(warc.weight(), DEFAULT_WEIGHT);
 
             let warcp = warc.clone();
-            assert_eq!(warcp.local(), DEFAULT_WEIGHT >> (i + 1));
-            assert_eq!(warc.local(), DEFAULT_WEIGHT >> (i + 1));
+            assert_eq!(warcp.local_weight(), DEFAULT_WEIGHT >> (i + 1));
+            assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> (i + 1));
 
             clones.push(warcp);
         }
 
-        assert_eq!(warc.local(), 1);
+        assert_eq!(warc.local_weight(), 1);
 
         let warcp = warc.clone();
-        assert_eq!(warcp.local(), DEFAULT_WEIGHT >> 1);
-        assert_eq!(warc.local(), DEFAULT_WEIGHT >> 1);
-        assert_eq!(warc.global(), (DEFAULT_WEIGHT << 1) - 1);
+        assert_eq!(warcp.local_weight(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.weight(), (DEFAULT_WEIGHT << 1) - 1);
 
         assert_eq!(
-            warc.global(),
-            warc.local() + warcp.local() + clones.iter().map(Warc::local).sum::<usize>(),
+            warc.weight(),
+            warc.local_weight() + warcp.local_weight() + clones.iter().map(Warc::local_weight).sum::<usize>(),
         );
 
         clones.clear();
-        assert_eq!(warc.global(), warc.local() + warcp.local());
+        assert_eq!(warc.weight(), warc.local_weight() + warcp.local_weight());
+    }
+
+    #[test]
+    fn weak() {
+        let warc = Warc::new(());
+        let mut weaks = Vec::with_capacity(16);
+
+        for i in 0..16 {
+            let weak = warc.downgrade();
+            assert_eq!(weak.local(), DEFAULT_WEIGHT >> (i + 1));
+
+            weaks.push(weak);
+        }
+
+        // 16 halvings of a single `DEFAULT_WEIGHT` mint should amortize down to a local pool of
+        // `1`, without ever touching the shared counter again.
+        assert_eq!(warc.weak(), 2 * DEFAULT_WEIGHT);
+
+        let weak = warc.downgrade();
+        assert_eq!(weak.local(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.weak(), 3 * DEFAULT_WEIGHT - 1);
+
+        weaks.push(weak);
+        assert_eq!(
+            warc.weak(),
+            DEFAULT_WEIGHT + weaks.iter().map(Weak::local).sum::<usize>() + (DEFAULT_WEIGHT >> 1),
+        );
+    }
+
+    #[test]
+    fn downgrade_upgrade() {
+        let warc = Warc::new(());
+        assert_eq!(warc.weak(), DEFAULT_WEIGHT);
+
+        let weak = warc.downgrade();
+        assert_eq!(weak.local(), DEFAULT_WEIGHT >> 1);
+        assert_eq!(warc.weak(), 2 * DEFAULT_WEIGHT);
+
+        let warcp = weak.upgrade().expect("value should still be alive");
+        assert_eq!(warcp.local_weight(), ADD_WEIGHT);
+        assert_eq!(warc.weight(), DEFAULT_WEIGHT + ADD_WEIGHT);
+
+        drop(warc);
+        drop(warcp);
+
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn upgrade_after_drop() {
+        let warc = Warc::new(());
+        let weak = warc.downgrade();
+
+        drop(warc);
+
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn try_unwrap() {
+        let warc = Warc::new(42);
+        let warcp = warc.clone();
+
+        let warc = warc.try_unwrap().unwrap_err();
+        assert_eq!(*warc, 42);
+
+        drop(warcp);
+        assert_eq!(warc.try_unwrap(), Ok(42));
+    }
+
+    #[test]
+    fn try_unwrap_with_weak() {
+        let warc = Warc::new(42);
+        let weak = warc.downgrade();
+
+        assert_eq!(warc.try_unwrap(), Ok(42));
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn into_inner() {
+        let warc = Warc::new(42);
+        let warcp = warc.clone();
+
+        assert_eq!(warc.into_inner(), None);
+        assert_eq!(warcp.into_inner(), Some(42));
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut warc = Warc::new(42);
+        let warcp = warc.clone();
+
+        assert!(warc.get_mut().is_none());
+
+        drop(warcp);
+        *warc.get_mut().unwrap() = 7;
+        assert_eq!(*warc, 7);
+    }
+
+    #[test]
+    fn get_mut_with_weak() {
+        let mut warc = Warc::new(42);
+        let weak = warc.downgrade();
+
+        assert!(warc.get_mut().is_none());
+
+        drop(weak);
+        *warc.get_mut().unwrap() = 7;
+        assert_eq!(*warc, 7);
+    }
+
+    #[test]
+    fn make_mut() {
+        let mut warc = Warc::new(42);
+        let warcp = warc.clone();
+
+        *warc.make_mut() = 7;
+        assert_eq!(*warc, 7);
+        assert_eq!(*warcp, 42);
+
+        *warc.make_mut() = 13;
+        assert_eq!(*warc, 13);
+    }
+
+    #[test]
+    fn make_mut_with_weak() {
+        let mut warc = Warc::new(42);
+        let weak = warc.downgrade();
+
+        *warc.make_mut() = 7;
+        assert_eq!(*warc, 7);
+        assert!(weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn from_box() {
+        let warc = Warc::from(Box::new(42));
+        assert_eq!(*warc, 42);
+    }
+
+    #[test]
+    fn unsized_coercion() {
+        let warc: Warc<dyn Display> = Warc::new(42);
+        assert_eq!(warc.to_string(), "42");
+    }
+
+    #[test]
+    fn slice() {
+        let warc: Warc<[u8]> = Warc::from(&[1, 2, 3][..]);
+        assert_eq!(&*warc, [1, 2, 3]);
+
+        let warc: Warc<[u8]> = (1..=3).collect();
+        assert_eq!(&*warc, [1, 2, 3]);
+    }
+
+    #[test]
+    fn ptr_eq() {
+        let warc = Warc::new(42);
+        let warcp = warc.clone();
+        let other = Warc::new(42);
+
+        assert!(Warc::ptr_eq(&warc, &warcp));
+        assert!(!Warc::ptr_eq(&warc, &other));
+    }
+
+    #[test]
+    fn raw_roundtrip() {
+        let warc = Warc::new(42);
+        let weight = warc.weight();
+
+        let ptr = warc.into_raw();
+        let warc = unsafe { Warc::from_raw(ptr) };
+
+        assert_eq!(*warc, 42);
+        assert_eq!(warc.weight(), weight);
+    }
+
+    #[test]
+    fn raw_roundtrip_with_concurrent_clones() {
+        let warc = Warc::new(42);
+        let warcp = warc.clone();
+        assert_eq!(warc.local_weight(), DEFAULT_WEIGHT >> 1);
+
+        // `into_raw` must leak exactly `RAW_WEIGHT`, regardless of what `warc`'s own weight
+        // happened to be whittled down to by unrelated clones.
+        let ptr = warc.into_raw();
+        assert_eq!(warcp.weight(), (DEFAULT_WEIGHT >> 1) + RAW_WEIGHT);
+
+        let warcpp = warcp.clone();
+        drop(warcp);
+
+        let warc = unsafe { Warc::from_raw(ptr) };
+        assert_eq!(*warc, 42);
+        assert_eq!(warc.local_weight(), RAW_WEIGHT);
+        assert_eq!(warc.weight(), warc.local_weight() + warcpp.local_weight());
     }
 }